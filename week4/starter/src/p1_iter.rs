@@ -31,6 +31,13 @@
 
 
 // Your implementation goes here!
+use std::iter::Fuse;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
 struct CacheableIterator<T, A>
     where T: Iterator<Item=A>, A: Clone {
     iter: T,
@@ -126,6 +133,282 @@ impl<T, U, A, B> Cartesian<T, U, A, B> for T
     }
 }
 
+// Below a certain range size, splitting further just adds thread overhead for no gain.
+const PAR_SPLIT_THRESHOLD: usize = 1024;
+
+/// A producer over a contiguous slice `[start, end)` of the flattened product
+/// `a x b`. This mirrors rayon's `Producer` trait: it knows its own length and
+/// can be split in half, and a leaf producer turns into a plain sequential
+/// iterator over its slice.
+struct CartesianProducer<A, B> {
+    a: Arc<Vec<A>>,
+    b: Arc<Vec<B>>,
+    start: usize,
+    end: usize,
+}
+
+impl<A, B> CartesianProducer<A, B>
+    where A: Clone, B: Clone {
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        let split = self.start + mid;
+        (
+            CartesianProducer { a: self.a.clone(), b: self.b.clone(), start: self.start, end: split },
+            CartesianProducer { a: self.a.clone(), b: self.b.clone(), start: split, end: self.end },
+        )
+    }
+
+    fn index(&self, i: usize) -> (A, B) {
+        let blen = self.b.len();
+        (self.a[i / blen].clone(), self.b[i % blen].clone())
+    }
+
+    fn into_vec(self) -> Vec<(A, B)> {
+        (self.start..self.end).map(|i| self.index(i)).collect()
+    }
+}
+
+/// Recursively splits `producer` while it's bigger than [`PAR_SPLIT_THRESHOLD`]
+/// and there's thread budget left to spend, spawning the left half onto a
+/// scoped thread while the current thread handles the right half, and joining
+/// the results back in index order.
+fn bridge<A, B>(producer: CartesianProducer<A, B>, threads: usize) -> Vec<(A, B)>
+    where A: Clone + Send + Sync, B: Clone + Send + Sync {
+    if threads <= 1 || producer.len() <= PAR_SPLIT_THRESHOLD {
+        return producer.into_vec();
+    }
+
+    let mid = producer.len() / 2;
+    let (left, right) = producer.split_at(mid);
+    let right_threads = threads / 2;
+    let left_threads = threads - right_threads;
+
+    thread::scope(|scope| {
+        let handle = scope.spawn(|| bridge(left, left_threads));
+        let mut right_result = bridge(right, right_threads);
+        let mut result = handle.join().unwrap();
+        result.append(&mut right_result);
+        result
+    })
+}
+
+/// A destination for [`collect_into_vec`](ParCartesianProduct::collect_into_vec):
+/// an uninitialized sub-range of the output buffer. Splitting a consumer in
+/// lockstep with its [`CartesianProducer`] keeps every leaf's slice disjoint,
+/// so leaves can write their results directly into the final buffer from
+/// different threads with no synchronization and no intermediate `Vec`s.
+struct CollectConsumer<'a, T> {
+    slice: &'a mut [MaybeUninit<T>],
+}
+
+impl<'a, T> CollectConsumer<'a, T> {
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at_mut(mid);
+        (CollectConsumer { slice: left }, CollectConsumer { slice: right })
+    }
+}
+
+/// Commits the elements written into `buf`'s spare capacity once dropped.
+/// Each leaf reports its count into `written` only after it finishes filling
+/// its slice, and the length is set only when `written` reaches `len`, so if a
+/// leaf panics partway through, the `Vec` stays empty rather than claiming
+/// uninitialized memory as initialized. Elements already written by completed
+/// leaves are then leaked, not dropped.
+///
+/// `buf` is a raw pointer rather than `&mut Vec<T>` so that the same buffer
+/// can simultaneously be viewed as `&mut [MaybeUninit<T>]` by the consumer
+/// tree without the borrow checker seeing two live mutable borrows.
+struct CollectGuard<'a, T> {
+    buf: *mut Vec<T>,
+    len: usize,
+    written: &'a AtomicUsize,
+    _marker: PhantomData<&'a mut Vec<T>>,
+}
+
+impl<'a, T> Drop for CollectGuard<'a, T> {
+    fn drop(&mut self) {
+        // SAFETY: `buf` was derived from a unique `&mut Vec<T>` that outlives
+        // this guard, and `written == len` only once every leaf has completed,
+        // so all of the first `len` elements were initialized.
+        if self.written.load(Ordering::SeqCst) == self.len {
+            unsafe {
+                (*self.buf).set_len(self.len);
+            }
+        }
+    }
+}
+
+/// Recursive counterpart to [`bridge`] that writes results directly into a
+/// [`CollectConsumer`]'s slice instead of returning a `Vec`.
+fn bridge_collect<A, B>(
+    producer: CartesianProducer<A, B>,
+    consumer: CollectConsumer<(A, B)>,
+    threads: usize,
+    written: &AtomicUsize,
+) where A: Clone + Send + Sync, B: Clone + Send + Sync {
+    if threads <= 1 || producer.len() <= PAR_SPLIT_THRESHOLD {
+        let len = producer.len();
+        for (offset, slot) in consumer.slice.iter_mut().enumerate() {
+            let value = producer.index(producer.start + offset);
+            // SAFETY: `slot` is part of this leaf's disjoint sub-slice, which
+            // no other leaf writes to, and it's written exactly once.
+            unsafe {
+                slot.as_mut_ptr().write(value);
+            }
+        }
+        written.fetch_add(len, Ordering::SeqCst);
+        return;
+    }
+
+    let mid = producer.len() / 2;
+    let (left_p, right_p) = producer.split_at(mid);
+    let (left_c, right_c) = consumer.split_at(mid);
+    let right_threads = threads / 2;
+    let left_threads = threads - right_threads;
+
+    thread::scope(|scope| {
+        scope.spawn(|| bridge_collect(left_p, left_c, left_threads, written));
+        bridge_collect(right_p, right_c, right_threads, written);
+    });
+}
+
+/// A parallel, indexed cartesian product: the full `a x b` result, computed by
+/// recursively splitting a [`CartesianProducer`] across scoped threads with
+/// `bridge` rather than iterating sequentially.
+struct ParCartesianProduct<A, B> {
+    producer: CartesianProducer<A, B>,
+}
+
+impl<A, B> ParCartesianProduct<A, B>
+    where A: Clone + Send + Sync, B: Clone + Send + Sync {
+    /// Runs the product to completion, splitting work across up to
+    /// [`available_parallelism`] threads.
+    fn collect(self) -> Vec<(A, B)> {
+        let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        bridge(self.producer, threads)
+    }
+
+    /// Like [`collect`](Self::collect), but writes results directly into a
+    /// single `Vec` preallocated to the product's length instead of
+    /// concatenating a `Vec` per thread.
+    fn collect_into_vec(self) -> Vec<(A, B)> {
+        let len = self.producer.len();
+        let mut buf: Vec<(A, B)> = Vec::with_capacity(len);
+        let written = AtomicUsize::new(0);
+        let buf_ptr: *mut Vec<(A, B)> = &mut buf;
+        let guard = CollectGuard { buf: buf_ptr, len, written: &written, _marker: PhantomData };
+
+        // SAFETY: `buf_ptr` is valid for this whole scope and nothing else
+        // touches `buf` until `guard` is dropped after `bridge_collect` returns.
+        // The spare capacity can exceed `len`, so it's cut to exactly `len`
+        // slots to match the producer.
+        let slice = unsafe { &mut (*buf_ptr).spare_capacity_mut()[..len] };
+        let consumer = CollectConsumer { slice };
+        let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        bridge_collect(self.producer, consumer, threads, &written);
+        drop(guard);
+
+        buf
+    }
+}
+
+trait ParCartesian<A, B>
+    where A: Clone + Send + Sync, B: Clone + Send + Sync {
+    fn par_cartesian_product<U>(self, other: U) -> ParCartesianProduct<A, B>
+        where U: Iterator<Item = B>;
+}
+
+impl<T, A, B> ParCartesian<A, B> for T
+    where T: Iterator<Item=A>, A: Clone + Send + Sync, B: Clone + Send + Sync {
+    fn par_cartesian_product<U>(self, other: U) -> ParCartesianProduct<A, B>
+        where U: Iterator<Item = B> {
+        let a: Vec<A> = self.collect();
+        let b: Vec<B> = other.collect();
+        let end = a.len() * b.len();
+        ParCartesianProduct {
+            producer: CartesianProducer { a: Arc::new(a), b: Arc::new(b), start: 0, end },
+        }
+    }
+}
+
+/// The shared state behind [`par_bridge`](ParBridge::par_bridge): a sequential
+/// iterator any number of worker threads can pull items from. Wrapping it in
+/// `.fuse()` latches exhaustion, so once a worker observes `None` every later
+/// `next()` call keeps returning `None` instead of spinning or resuming.
+struct ParBridgeIterator<T, I: Iterator<Item = T>> {
+    iter: Arc<Mutex<Fuse<I>>>,
+}
+
+impl<T: Send, I: Iterator<Item = T> + Send> ParBridgeIterator<T, I> {
+    /// Runs `f` on every item using `available_parallelism` worker threads.
+    /// Each worker locks the iterator only to pull the next item, then runs
+    /// `f` outside the lock so workers never block each other on user code.
+    fn for_each<F>(self, f: F)
+        where F: Fn(T) + Sync {
+        let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                let iter = self.iter.clone();
+                let f = &f;
+                scope.spawn(move || loop {
+                    let next = iter.lock().unwrap().next();
+                    match next {
+                        Some(item) => f(item),
+                        None => break,
+                    }
+                });
+            }
+        });
+    }
+
+    /// Runs `map` on every item, has each worker fold its results together
+    /// with `reduce` starting from `id()`, then combines the per-worker
+    /// accumulators the same way.
+    fn map_reduce<A, M, R>(self, map: M, id: impl Fn() -> A + Sync + Send, reduce: R) -> A
+        where A: Send, M: Fn(T) -> A + Sync, R: Fn(A, A) -> A + Sync {
+        let threads = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| {
+                    let iter = self.iter.clone();
+                    let map = &map;
+                    let id = &id;
+                    let reduce = &reduce;
+                    scope.spawn(move || {
+                        let mut acc = id();
+                        loop {
+                            let next = iter.lock().unwrap().next();
+                            match next {
+                                Some(item) => acc = reduce(acc, map(item)),
+                                None => break,
+                            }
+                        }
+                        acc
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .fold(id(), &reduce)
+        })
+    }
+}
+
+trait ParBridge<T: Send>: Iterator<Item = T> + Send + Sized {
+    fn par_bridge(self) -> ParBridgeIterator<T, Self>;
+}
+
+impl<T: Send, I: Iterator<Item = T> + Send> ParBridge<T> for I {
+    fn par_bridge(self) -> ParBridgeIterator<T, Self> {
+        ParBridgeIterator { iter: Arc::new(Mutex::new(self.fuse())) }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -150,4 +433,43 @@ mod test {
             vec![(1, 4), (1, 5), (2, 4), (2, 5), (3, 4), (3,5)]
         );
     }
+
+    #[test]
+    fn par_cartesian_product_test() {
+        let v1 = vec![1, 2, 3];
+        let v2 = vec![4, 5];
+        let mut product = v1.into_iter().par_cartesian_product(v2.into_iter()).collect();
+        product.sort();
+        assert_eq!(
+            product,
+            vec![(1, 4), (1, 5), (2, 4), (2, 5), (3, 4), (3, 5)]
+        );
+    }
+
+    #[test]
+    fn par_cartesian_product_collect_into_vec_test() {
+        let v1 = vec![1, 2, 3];
+        let v2 = vec![4, 5];
+        let mut product = v1.into_iter().par_cartesian_product(v2.into_iter()).collect_into_vec();
+        product.sort();
+        assert_eq!(
+            product,
+            vec![(1, 4), (1, 5), (2, 4), (2, 5), (3, 4), (3, 5)]
+        );
+    }
+
+    #[test]
+    fn par_bridge_for_each_test() {
+        let sum = Mutex::new(0);
+        (1..=100).par_bridge().for_each(|i| {
+            *sum.lock().unwrap() += i;
+        });
+        assert_eq!(*sum.lock().unwrap(), 5050);
+    }
+
+    #[test]
+    fn par_bridge_map_reduce_test() {
+        let total = (1..=100).par_bridge().map_reduce(|i| i, || 0, |a, b| a + b);
+        assert_eq!(total, 5050);
+    }
 }
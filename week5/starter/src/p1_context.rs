@@ -22,10 +22,16 @@
 //! thread-safe, if you need to use interior mutability, you should use a 
 //! [`Mutex`](https://doc.rust-lang.org/std/sync/struct.Mutex.html) instead of a `RefCell`.
 
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
 
 pub struct Context<T> {
-    values: Arc<Mutex<Vec<T>>>
+    // Keyed by `ThreadId` rather than a single shared stack, so a value `set`
+    // on one thread is invisible to unrelated threads: each thread sees only
+    // its own call stack's view of the context.
+    stacks: Mutex<HashMap<ThreadId, Vec<T>>>
 }
 
 pub struct Dropper<'a, T: Copy> {
@@ -40,25 +46,99 @@ impl<'a, T: Copy> Drop for Dropper<'a, T> {
 
 impl<T: Copy> Context<T> {
     pub fn new() -> Context<T> {
-        return Context { values: Arc::new(Mutex::new(vec![])) };
+        return Context { stacks: Mutex::new(HashMap::new()) };
     }
 
     pub fn set(&self, new: T) -> Dropper<T> {
-        let mut data = self.values.lock().unwrap();
-        (*data).push(new);
+        let mut stacks = self.stacks.lock().unwrap();
+        stacks.entry(thread::current().id()).or_default().push(new);
         return Dropper { ctx: self }
     }
 
     pub fn get(&self) -> Option<T> {
-        match self.values.lock().unwrap().last() {
+        let stacks = self.stacks.lock().unwrap();
+        match stacks.get(&thread::current().id()).and_then(|stack| stack.last()) {
             None => None,
             Some(v) => Some(v.clone())
         }
     }
 
     pub fn pop(&self) {
-        let mut data = self.values.lock().unwrap();
-        (*data).pop();
+        let mut stacks = self.stacks.lock().unwrap();
+        let id = thread::current().id();
+        // Remove the entry entirely once its stack is empty, rather than
+        // leaving a dangling `Vec::new()` behind: `ThreadId`s are never
+        // reused, and `Context::scope` can spawn a fresh pool of worker
+        // threads on every call, so a long-lived `Context` would otherwise
+        // accumulate one abandoned entry per worker thread per call forever.
+        if let Some(stack) = stacks.get_mut(&id) {
+            stack.pop();
+            if stack.is_empty() {
+                stacks.remove(&id);
+            }
+        }
+    }
+}
+
+// A small internal pool. Four workers is plenty for exercising dynamically
+// scoped values across real thread hand-offs without spawning a thread per task.
+const SCOPE_POOL_SIZE: usize = 4;
+
+type Job<'env> = Box<dyn FnOnce() + Send + 'env>;
+
+/// Handle passed to the closure given to [`Context::scope`]. Use [`Scope::spawn`]
+/// to queue work onto the scope's internal thread pool.
+pub struct Scope<'env, T: Copy> {
+    ctx: &'env Context<T>,
+    sender: Sender<Job<'env>>,
+}
+
+impl<'env, T: Copy + Send> Scope<'env, T> {
+    /// Queues `f` onto the pool. Whatever value is on top of *this* thread's
+    /// context stack right now is captured, and re-established (pushed, run,
+    /// popped) around `f` on whichever worker thread ends up executing it --
+    /// so the context behaves as if `f` had run inline, even though it may
+    /// run on a different thread.
+    pub fn spawn<F>(&self, f: F)
+        where F: FnOnce() + Send + 'env {
+        let ctx = self.ctx;
+        let inherited = ctx.get();
+        let job: Job<'env> = Box::new(move || {
+            let _guard = inherited.map(|value| ctx.set(value));
+            f();
+        });
+        self.sender.send(job).unwrap();
+    }
+}
+
+impl<T: Copy + Send> Context<T> {
+    /// Runs `body` with a handle onto a small internal thread pool backing
+    /// this context. This makes `Context` usable as task-local configuration
+    /// in parallel code: work queued through [`Scope::spawn`] inherits the
+    /// value visible at the point it was spawned, even though the worker
+    /// thread that actually runs it has its own, otherwise independent,
+    /// context stack.
+    pub fn scope<'env, F, R>(&'env self, body: F) -> R
+        where F: FnOnce(&Scope<'env, T>) -> R {
+        // `receiver` is declared outside `thread::scope` (rather than inside
+        // its closure) so it's still alive when `thread::scope` joins the
+        // workers after the closure returns -- the workers' borrows of it
+        // must outlive the closure body, not just the `body(...)` call.
+        let (sender, receiver) = channel::<Job<'env>>();
+        let receiver = Mutex::new(receiver);
+
+        thread::scope(|pool| {
+            for _ in 0..SCOPE_POOL_SIZE {
+                let receiver = &receiver;
+                pool.spawn(move || {
+                    while let Ok(job) = receiver.lock().unwrap().recv() {
+                        job();
+                    }
+                });
+            }
+
+            body(&Scope { ctx: self, sender })
+        })
     }
 }
 
@@ -89,4 +169,32 @@ mod test {
 
         assert_eq!(CTX.get(), Some(0));
     }
+
+    lazy_static! {
+        static ref SCOPE_CTX: Context<usize> = Context::new();
+    }
+
+    #[test]
+    fn context_scope_test() {
+        use std::sync::mpsc::channel;
+
+        let _g = SCOPE_CTX.set(7);
+
+        let (tx, rx) = channel();
+        SCOPE_CTX.scope(|scope| {
+            for _ in 0..8 {
+                let tx = tx.clone();
+                scope.spawn(move || {
+                    tx.send(SCOPE_CTX.get()).unwrap();
+                });
+            }
+        });
+        drop(tx);
+
+        for seen in rx {
+            assert_eq!(seen, Some(7));
+        }
+
+        assert_eq!(SCOPE_CTX.get(), Some(7));
+    }
 }
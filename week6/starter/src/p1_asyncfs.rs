@@ -22,7 +22,10 @@
 //! check this is true by uncommenting `read_bad_scope_test` below, and ensuring it does not compile.
 
 use std::{fs::File, future::Future, io, marker::PhantomData, pin::Pin, task::{Context, Poll}, thread};
+use std::cell::UnsafeCell;
 use std::io::Read;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::task::Waker;
 
@@ -108,6 +111,232 @@ impl<'a> Future for ReadFile<'a> {
     }
 }
 
+/// Outcome of [`Deque::steal`]: either there was nothing to take, there was
+/// something but a racing owner/stealer won it first (the caller should try
+/// again or move on to another victim), or the steal succeeded.
+enum Steal<T> {
+    Empty,
+    Retry,
+    Success(T),
+}
+
+/// Slot `i` holds the result of reading `files[i]`, filled in by whichever
+/// worker ends up running that job.
+type ReadManyResults = Arc<Mutex<Vec<Option<io::Result<Vec<u8>>>>>>;
+
+/// A fixed-capacity Chase-Lev work-stealing deque. The owning thread `push`es
+/// and `pop`s from the bottom (LIFO); any thread may `steal` from the top
+/// (FIFO). Unlike the textbook version this never grows the backing buffer --
+/// [`read_many_async`] knows the total job count up front, so each worker's
+/// deque is sized generously enough at construction time that it's never
+/// pushed to beyond capacity.
+struct Deque<T> {
+    buf: UnsafeCell<Box<[MaybeUninit<T>]>>,
+    cap: usize,
+    top: AtomicUsize,
+    bottom: AtomicUsize,
+}
+
+// SAFETY: `Deque<T>` only ever moves `T` values between threads (one owner
+// `push`/`pop`s, any thread may `steal`), never shares `&T` access to them, so
+// it's `Send`/`Sync` whenever `T` itself is safe to send between threads.
+unsafe impl<T: Send> Send for Deque<T> {}
+unsafe impl<T: Send> Sync for Deque<T> {}
+
+impl<T> Deque<T> {
+    fn new(cap: usize) -> Self {
+        let buf = (0..cap).map(|_| MaybeUninit::uninit()).collect();
+        Deque { buf: UnsafeCell::new(buf), cap, top: AtomicUsize::new(0), bottom: AtomicUsize::new(0) }
+    }
+
+    /// Owner-only: append a job. Panics in debug builds if `cap` is exceeded.
+    fn push(&self, value: T) {
+        let b = self.bottom.load(Ordering::Relaxed);
+        let t = self.top.load(Ordering::Acquire);
+        debug_assert!(b - t < self.cap, "Deque::push exceeded its fixed capacity");
+        // SAFETY: `push` is only ever called by the owning thread, and callers
+        // never push more than `cap` jobs (checked by the `debug_assert!` above
+        // in debug builds only), so slot `b % cap` isn't still holding a value
+        // that `pop`/`steal` haven't taken yet.
+        unsafe {
+            (*self.buf.get())[b % self.cap].write(value);
+        }
+        self.bottom.store(b + 1, Ordering::Release);
+    }
+
+    /// Owner-only: take the most recently pushed job, racing any concurrent
+    /// stealers only when a single job is left.
+    fn pop(&self) -> Option<T> {
+        let b = self.bottom.load(Ordering::Relaxed);
+        if b == 0 {
+            return None;
+        }
+        let b = b - 1;
+        self.bottom.store(b, Ordering::Relaxed);
+        std::sync::atomic::fence(Ordering::SeqCst);
+        let t = self.top.load(Ordering::Acquire);
+
+        if t > b {
+            // A stealer already took everything up to and past `b`.
+            self.bottom.store(b + 1, Ordering::Relaxed);
+            return None;
+        }
+
+        // SAFETY: `t <= b` was just confirmed above, so slot `b % cap` holds a
+        // value `push` wrote and no stealer has claimed it yet; only the
+        // owner calls `pop`, so nothing else reads this slot concurrently.
+        let value = unsafe { (*self.buf.get())[b % self.cap].assume_init_read() };
+        if t == b {
+            // Last job: race any stealer for it via the same CAS on `top`.
+            let won = self.top.compare_exchange(t, t + 1, Ordering::SeqCst, Ordering::Relaxed).is_ok();
+            self.bottom.store(b + 1, Ordering::Relaxed);
+            if !won {
+                // A stealer's CAS won instead; it owns `value`, we don't.
+                std::mem::forget(value);
+                return None;
+            }
+        }
+        Some(value)
+    }
+
+    /// Any thread: take the oldest job. Distinguishes a genuinely empty deque
+    /// from one where a racing pop/steal must be retried, so callers can back
+    /// off instead of busy-spinning on contention.
+    fn steal(&self) -> Steal<T> {
+        let t = self.top.load(Ordering::Acquire);
+        std::sync::atomic::fence(Ordering::SeqCst);
+        let b = self.bottom.load(Ordering::Acquire);
+        if t >= b {
+            return Steal::Empty;
+        }
+
+        // SAFETY: `t < b` was just confirmed above, so slot `t % cap` holds a
+        // value `push` wrote. Reading it here is only safe to *act on* if the
+        // following CAS on `top` succeeds -- otherwise another thread reads
+        // (and keeps) the same bytes, so the `Err` arm below forgets this
+        // copy instead of dropping it, to avoid a double-drop/double-read.
+        let value = unsafe { (*self.buf.get())[t % self.cap].assume_init_read() };
+        match self.top.compare_exchange(t, t + 1, Ordering::SeqCst, Ordering::Relaxed) {
+            Ok(_) => Steal::Success(value),
+            Err(_) => {
+                // Another stealer (or the owner's `pop`) already won this slot.
+                std::mem::forget(value);
+                Steal::Retry
+            }
+        }
+    }
+}
+
+/// Each worker drains its own deque, then steals round-robin from its
+/// siblings until a full sweep comes back with nothing to steal and nothing
+/// to retry, at which point there's provably no more work anywhere (no new
+/// jobs are ever pushed after the initial seeding).
+fn read_many_worker(
+    id: usize,
+    queues: Arc<Vec<Deque<(usize, File)>>>,
+    results: ReadManyResults,
+    outstanding: Arc<AtomicUsize>,
+    waker: Arc<Mutex<Option<Waker>>>,
+) {
+    let workers = queues.len();
+    loop {
+        let job = queues[id].pop().or_else(|| loop {
+            let mut saw_retry = false;
+            for offset in 1..workers {
+                match queues[(id + offset) % workers].steal() {
+                    Steal::Success(job) => return Some(job),
+                    Steal::Retry => saw_retry = true,
+                    Steal::Empty => {}
+                }
+            }
+            if !saw_retry {
+                return None;
+            }
+            thread::yield_now();
+        });
+
+        let (index, mut file) = match job {
+            Some(job) => job,
+            None => break,
+        };
+
+        let mut buf = vec![];
+        let result = file.read_to_end(&mut buf).map(|_| buf);
+        results.lock().unwrap()[index] = Some(result);
+
+        if outstanding.fetch_sub(1, Ordering::AcqRel) == 1 {
+            if let Some(waker) = waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// The future returned by [`read_many_async`].
+pub struct ReadManyFiles {
+    results: ReadManyResults,
+    outstanding: Arc<AtomicUsize>,
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl Future for ReadManyFiles {
+    type Output = Vec<io::Result<Vec<u8>>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Store the waker *before* checking `outstanding`: if a worker
+        // finishes the last job between these two steps, it'll see the waker
+        // we just stored and wake it, and we'll also notice `outstanding` has
+        // hit zero ourselves below. Checking first and storing after would
+        // risk the worker finding no waker to wake and us never getting polled
+        // again.
+        *self.waker.lock().unwrap() = Some(cx.waker().clone());
+
+        if self.outstanding.load(Ordering::Acquire) == 0 {
+            let mut results = self.results.lock().unwrap();
+            let output = std::mem::take(&mut *results)
+                .into_iter()
+                .map(|r| r.unwrap_or_else(|| Err(io::Error::other("file was never read"))))
+                .collect();
+            Poll::Ready(output)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Reads many files concurrently, backed by a bounded pool of worker threads
+/// that each own a Chase-Lev work-stealing deque rather than spawning one
+/// thread per file. The main thread seeds the deques round-robin with
+/// `(index, File)` jobs; the returned future resolves once every job has
+/// completed, with results in the same order as `files`.
+pub fn read_many_async(files: Vec<File>) -> ReadManyFiles {
+    let total = files.len();
+    let results = Arc::new(Mutex::new((0..total).map(|_| None).collect()));
+    let outstanding = Arc::new(AtomicUsize::new(total));
+    let waker: Arc<Mutex<Option<Waker>>> = Arc::new(Mutex::new(None));
+
+    if total > 0 {
+        let workers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1).min(total);
+        let cap = total.div_ceil(workers);
+        let queues: Arc<Vec<Deque<(usize, File)>>> =
+            Arc::new((0..workers).map(|_| Deque::new(cap)).collect());
+
+        for (index, file) in files.into_iter().enumerate() {
+            queues[index % workers].push((index, file));
+        }
+
+        for id in 0..workers {
+            let queues = queues.clone();
+            let results = results.clone();
+            let outstanding = outstanding.clone();
+            let waker = waker.clone();
+            thread::spawn(move || read_many_worker(id, queues, results, outstanding, waker));
+        }
+    }
+
+    ReadManyFiles { results, outstanding, waker }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -133,4 +362,23 @@ mod test {
     //   let buf = future.await.unwrap();
     //   assert_eq!(String::from_utf8(buf).unwrap(), "hello world");
     // }
+
+    #[tokio::test]
+    async fn read_many_test() {
+        let paths: Vec<_> = (0..16)
+            .map(|i| std::env::temp_dir().join(format!("read_many_{i}.txt")))
+            .collect();
+        let contents: Vec<_> = (0..16).map(|i| format!("file number {i}")).collect();
+        for (path, contents) in paths.iter().zip(&contents) {
+            fs::write(path, contents).unwrap();
+        }
+
+        let files = paths.iter().map(|path| File::open(path).unwrap()).collect();
+        let results = read_many_async(files).await;
+
+        assert_eq!(results.len(), paths.len());
+        for (result, contents) in results.into_iter().zip(&contents) {
+            assert_eq!(String::from_utf8(result.unwrap()).unwrap(), *contents);
+        }
+    }
 }